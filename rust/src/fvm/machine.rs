@@ -2,8 +2,10 @@ use std::collections::HashMap;
 use std::convert::TryFrom;
 use std::mem;
 use std::ptr;
-use std::sync::{atomic::AtomicU64, Mutex};
+use std::sync::atomic::{AtomicU32, Ordering};
+use std::sync::Mutex;
 
+use ahash::RandomState;
 use anyhow::Error;
 use blockstore::cgo::CgoBlockstore;
 use blockstore::{Block, Blockstore, MemoryBlockstore};
@@ -30,25 +32,171 @@ use once_cell::sync::Lazy;
 use super::types::*;
 use crate::util::api::init_log;
 
+/// Hands ownership of `vec` to the caller as a raw pointer + length pair.
+/// The caller is expected to reconstruct and drop the `Vec` (see the
+/// `fil_destroy_*` functions below) once it is done with the buffer.
+fn vec_into_raw_parts<T>(vec: Vec<T>) -> (*mut T, libc::size_t) {
+    let mut vec = mem::ManuallyDrop::new(vec.into_boxed_slice());
+    (vec.as_mut_ptr(), vec.len())
+}
+
+fn bytes_into_raw_parts(bytes: Vec<u8>) -> (*mut u8, libc::size_t) {
+    vec_into_raw_parts(bytes)
+}
+
+/// Marshals the fields of `apply_ret` that callers care about onto
+/// `response`. Shared by the single-message and batch execute entry points.
+fn fill_execute_response(response: &mut fil_FvmMachineExecuteResponse, apply_ret: ApplyRet) {
+    let (return_ptr, return_len) = bytes_into_raw_parts(apply_ret.msg_receipt.return_data.to_vec());
+    let (penalty_hi, penalty_lo) = token_amount_to_hi_lo(&apply_ret.penalty);
+    let (miner_tip_hi, miner_tip_lo) = token_amount_to_hi_lo(&apply_ret.miner_tip);
+
+    response.exit_code = apply_ret.msg_receipt.exit_code.value() as u64;
+    response.return_ptr = return_ptr;
+    response.return_len = return_len;
+    response.gas_used = apply_ret.msg_receipt.gas_used as u64;
+    response.penalty_hi = penalty_hi;
+    response.penalty_lo = penalty_lo;
+    response.miner_tip_hi = miner_tip_hi;
+    response.miner_tip_lo = miner_tip_lo;
+
+    if let Some(failure_info) = apply_ret.failure_info {
+        response.failure_info = rust_str_to_c_str(format!("{}", failure_info));
+    }
+}
+
 type CgoMachine = Machine<CgoBlockstore, CgoExterns>;
 
-static FVM_MAP: Lazy<Mutex<HashMap<u64, CgoMachine>>> =
-    Lazy::new(|| Mutex::new(HashMap::with_capacity(1)));
+/// Number of independent shards backing the machine registry. Message
+/// execution only ever locks the one shard holding the target machine, so
+/// concurrent calls against different machines don't serialize on each
+/// other.
+const NUM_SHARDS: usize = 16;
+
+/// A registry slot is keyed by the returned handle's low 32 bits; the high
+/// 32 bits carry the slot's generation so a handle from a machine that has
+/// since been dropped (and whose slot was recycled) is rejected instead of
+/// silently aliasing whatever machine now lives in that slot.
+struct MachineSlot {
+    generation: u32,
+    machine: CgoMachine,
+    /// Per-message wasmtime fuel budget configured for this machine at
+    /// creation time (see `fil_FvmConfig::fuel_limit`). Zero means no
+    /// budget is enforced.
+    fuel_limit: u64,
+}
+
+type Shard = Mutex<HashMap<u32, MachineSlot, RandomState>>;
+
+static HASHER: Lazy<RandomState> = Lazy::new(RandomState::new);
+
+static FVM_SHARDS: Lazy<Vec<Shard>> = Lazy::new(|| {
+    (0..NUM_SHARDS)
+        .map(|_| Mutex::new(HashMap::with_hasher(HASHER.clone())))
+        .collect()
+});
+
+static NEXT_SLOT: AtomicU32 = AtomicU32::new(0);
 
-const NEXT_ID: AtomicU64 = AtomicU64::new(0);
+/// Slots freed by `remove_fvm_machine`, along with the generation the next
+/// occupant of that slot should be stamped with.
+static FREE_SLOTS: Lazy<Mutex<Vec<(u32, u32)>>> = Lazy::new(|| Mutex::new(Vec::new()));
+
+fn pack_handle(slot: u32, generation: u32) -> u64 {
+    ((generation as u64) << 32) | slot as u64
+}
+
+fn unpack_handle(id: u64) -> (u32, u32) {
+    (id as u32, (id >> 32) as u32)
+}
 
-fn add_fvm_machine(machine: CgoMachine) -> u64 {
-    let next_id = NEXT_ID.fetch_add(1, std::sync::atomic::Ordering::SeqCst);
-    let mut machines = FVM_MAP.lock().unwrap();
-    machines.insert(next_id, machine);
-    next_id
+fn shard_for(slot: u32) -> &'static Shard {
+    &FVM_SHARDS[slot as usize % NUM_SHARDS]
 }
 
-fn get_default_config() -> fvm::Config {
+fn add_fvm_machine(machine: CgoMachine, fuel_limit: u64) -> u64 {
+    let (slot, generation) = FREE_SLOTS
+        .lock()
+        .unwrap()
+        .pop()
+        .unwrap_or_else(|| (NEXT_SLOT.fetch_add(1, Ordering::SeqCst), 0));
+
+    shard_for(slot).lock().unwrap().insert(
+        slot,
+        MachineSlot {
+            generation,
+            machine,
+            fuel_limit,
+        },
+    );
+
+    pack_handle(slot, generation)
+}
+
+fn remove_fvm_machine(machine_id: u64) -> Option<CgoMachine> {
+    let (slot, generation) = unpack_handle(machine_id);
+    let mut shard = shard_for(slot).lock().unwrap();
+    match shard.get(&slot) {
+        Some(existing) if existing.generation == generation => {
+            let removed = shard.remove(&slot).unwrap();
+            drop(shard);
+            FREE_SLOTS
+                .lock()
+                .unwrap()
+                .push((slot, generation.wrapping_add(1)));
+            Some(removed.machine)
+        }
+        _ => None,
+    }
+}
+
+/// Network versions this build of the FVM understands. A host should call
+/// `fil_fvm_supported_features` with the network version it intends to use
+/// and check the response before calling `fil_create_fvm_machine`, rather
+/// than discovering incompatibility from a `Machine::new` error.
+const MIN_SUPPORTED_NETWORK_VERSION: u32 = 16;
+const MAX_SUPPORTED_NETWORK_VERSION: u32 = 19;
+
+/// Implicit message application (cron, reward distribution, etc.) is only
+/// wired up from this network version onward.
+const MIN_IMPLICIT_MESSAGE_NETWORK_VERSION: u32 = 17;
+
+/// Actor bundle version a host should load for each supported network
+/// version, indexed by `network_version - MIN_SUPPORTED_NETWORK_VERSION`.
+/// This is what lets a host actually negotiate which bundle to fetch,
+/// rather than just learning a yes/no answer.
+const ACTORS_BUNDLE_VERSIONS: [u32; (MAX_SUPPORTED_NETWORK_VERSION - MIN_SUPPORTED_NETWORK_VERSION + 1) as usize] =
+    [8, 9, 9, 10];
+
+/// Returns the actor bundle version for `network_version`, or 0 if it's
+/// outside the range this build supports.
+fn actors_bundle_version(network_version: u32) -> u32 {
+    if !(MIN_SUPPORTED_NETWORK_VERSION..=MAX_SUPPORTED_NETWORK_VERSION).contains(&network_version)
+    {
+        return 0;
+    }
+    ACTORS_BUNDLE_VERSIONS[(network_version - MIN_SUPPORTED_NETWORK_VERSION) as usize]
+}
+
+/// Builds the wasmtime/fvm `Config` for a machine from the host-supplied
+/// tuning knobs. Fuel consumption is only turned on when the caller asked
+/// for a budget (`fvm_config.fuel_limit > 0`) — a `Store` with consumption
+/// enabled starts at zero fuel until explicitly topped up, so enabling it
+/// unconditionally would make every message on an unbudgeted machine trap
+/// with an out-of-fuel error.
+fn build_config(fvm_config: fil_FvmConfig) -> fvm::Config {
+    let mut engine = wasmtime::Config::new();
+    if fvm_config.fuel_limit > 0 {
+        engine.consume_fuel(true);
+    }
+    if fvm_config.max_wasm_stack > 0 {
+        engine.max_wasm_stack(fvm_config.max_wasm_stack as usize);
+    }
+
     Config {
-        initial_pages: 1024, //FIXME
-        max_pages: 32768,    // FIXME
-        engine: wasmtime::Config::new(),
+        initial_pages: fvm_config.initial_pages as usize,
+        max_pages: fvm_config.max_pages as usize,
+        engine,
     }
 }
 
@@ -56,8 +204,11 @@ fn get_default_config() -> fvm::Config {
 /// for some types is due to the generated bindings not liking the
 /// 32bit types as incoming args
 ///
+/// The `libc::size_t`/pointer-width handling and the CGO blockstore/externs
+/// callback ABI used here are the same across Linux, macOS and Windows, so
+/// unlike earlier versions of this function there is no per-target split —
+/// one definition builds and runs identically on all three.
 #[no_mangle]
-#[cfg(not(target_os = "windows"))]
 pub unsafe extern "C" fn fil_create_fvm_machine(
     fvm_version: fil_FvmRegisteredVersion,
     chain_epoch: u64,
@@ -67,6 +218,7 @@ pub unsafe extern "C" fn fil_create_fvm_machine(
     state_root_len: libc::size_t,
     blockstore_id: u64,
     externs_id: u64,
+    fvm_config: fil_FvmConfig,
 ) -> *mut fil_CreateFvmMachineResponse {
     catch_panic_response(|| {
         init_log();
@@ -75,7 +227,7 @@ pub unsafe extern "C" fn fil_create_fvm_machine(
 
         let mut response = fil_CreateFvmMachineResponse::default();
 
-        let config = get_default_config();
+        let config = build_config(fvm_config);
         let chain_epoch = chain_epoch as ChainEpoch;
         let token_amount = TokenAmount::from_u64(token_amount);
         let token_amount = if token_amount.is_some() {
@@ -119,7 +271,7 @@ pub unsafe extern "C" fn fil_create_fvm_machine(
         match machine {
             Ok(machine) => {
                 response.status_code = FCPResponseStatus::FCPNoError;
-                response.machine_id = add_fvm_machine(machine);
+                response.machine_id = add_fvm_machine(machine, fvm_config.fuel_limit);
             }
             Err(err) => {
                 response.status_code = FCPResponseStatus::FCPUnclassifiedError;
@@ -134,6 +286,47 @@ pub unsafe extern "C" fn fil_create_fvm_machine(
     })
 }
 
+#[no_mangle]
+pub unsafe extern "C" fn fil_fvm_supported_features(
+    network_version: u64,
+) -> *mut fil_FvmFeaturesResponse {
+    catch_panic_response(|| {
+        init_log();
+
+        info!("fil_fvm_supported_features: start");
+
+        let mut response = fil_FvmFeaturesResponse::default();
+        response.min_network_version = MIN_SUPPORTED_NETWORK_VERSION;
+        response.max_network_version = MAX_SUPPORTED_NETWORK_VERSION;
+
+        match NetworkVersion::try_from(network_version as u32) {
+            Ok(_) if (MIN_SUPPORTED_NETWORK_VERSION..=MAX_SUPPORTED_NETWORK_VERSION)
+                .contains(&(network_version as u32)) =>
+            {
+                response.status_code = FCPResponseStatus::FCPNoError;
+                response.actors_bundle_version = actors_bundle_version(network_version as u32);
+                response.actors_bundle_supported = true;
+                response.implicit_messages_supported =
+                    network_version as u32 >= MIN_IMPLICIT_MESSAGE_NETWORK_VERSION;
+            }
+            Ok(_) => {
+                response.status_code = FCPResponseStatus::FCPNoError;
+                response.actors_bundle_version = 0;
+                response.actors_bundle_supported = false;
+                response.implicit_messages_supported = false;
+            }
+            Err(err) => {
+                response.status_code = FCPResponseStatus::FCPUnclassifiedError;
+                response.error_msg = rust_str_to_c_str(format!("{:?}", err));
+            }
+        }
+
+        info!("fil_fvm_supported_features: end");
+
+        raw_ptr(response)
+    })
+}
+
 #[no_mangle]
 pub unsafe extern "C" fn fil_drop_fvm_machine(machine_id: u64) -> *mut fil_DropFvmMachineResponse {
     catch_panic_response(|| {
@@ -143,10 +336,9 @@ pub unsafe extern "C" fn fil_drop_fvm_machine(machine_id: u64) -> *mut fil_DropF
 
         let mut response = fil_DropFvmMachineResponse::default();
 
-        let mut machines = FVM_MAP.lock().unwrap();
-        let machine = machines.remove(&machine_id);
-        match machine {
+        match remove_fvm_machine(machine_id) {
             Some(machine) => {
+                drop(machine);
                 response.status_code = FCPResponseStatus::FCPNoError;
             }
             None => {
@@ -189,11 +381,24 @@ pub unsafe extern "C" fn fil_fvm_machine_execute_message(
             }
         };
 
-        let mut machines = FVM_MAP.lock().unwrap();
-        let mut machine = machines.get_mut(&machine_id);
-        match machine {
-            Some(machine) => {
-                let apply_ret = match machine.execute_message(message, apply_kind) {
+        let (slot, generation) = unpack_handle(machine_id);
+        let mut shard = shard_for(slot).lock().unwrap();
+        match shard.get_mut(&slot) {
+            Some(found) if found.generation == generation => {
+                // Fuel lives on the machine's own `Store`, not on the
+                // (potentially pooled/shared) `Engine` returned by
+                // `engine()` — resetting it here, rather than adding to
+                // whatever was left over, is what makes the budget apply
+                // to *this* message instead of accumulating across calls.
+                if found.fuel_limit > 0 {
+                    if let Err(err) = found.machine.store_mut().set_fuel(found.fuel_limit) {
+                        response.status_code = FCPResponseStatus::FCPUnclassifiedError;
+                        response.error_msg = rust_str_to_c_str(format!("{:?}", err));
+                        return raw_ptr(response);
+                    }
+                }
+
+                let apply_ret = match found.machine.execute_message(message, apply_kind) {
                     Ok(x) => x,
                     Err(err) => {
                         response.status_code = FCPResponseStatus::FCPUnclassifiedError;
@@ -203,9 +408,12 @@ pub unsafe extern "C" fn fil_fvm_machine_execute_message(
                 };
 
                 response.status_code = FCPResponseStatus::FCPNoError;
-                // FIXME: Return relevant fields of ApplyRet
+                fill_execute_response(&mut response, apply_ret);
+                if found.fuel_limit > 0 {
+                    response.fuel_remaining = found.machine.store_mut().get_fuel().unwrap_or(0);
+                }
             }
-            None => {
+            _ => {
                 response.status_code = FCPResponseStatus::FCPUnclassifiedError;
                 response.error_msg = rust_str_to_c_str(format!("invalid machine id"));
             }
@@ -217,29 +425,191 @@ pub unsafe extern "C" fn fil_fvm_machine_execute_message(
     })
 }
 
+/// Batch form of `fil_fvm_machine_execute_message`: acquires the machine's
+/// shard lock once and applies every message in `messages`/`apply_kinds`
+/// (parallel arrays) against it, instead of paying the lock-acquisition and
+/// FFI round-trip cost per message. Explicit messages fail fast: after the
+/// first hard error on an explicit message, every later explicit message is
+/// not applied and gets a "skipped" error response, and the failing index is
+/// reported via `error_index`. Implicit messages keep being applied even
+/// after such a failure, matching how a tipset's cron/reward messages are
+/// expected to run regardless of a user message's outcome. The batch
+/// returns one response per input message, in input order.
 #[no_mangle]
-pub unsafe extern "C" fn fil_fvm_machine_finish_message(
+pub unsafe extern "C" fn fil_fvm_machine_execute_messages(
     machine_id: u64,
-    // TODO: actual message
-) {
-    // catch_panic_response(|| {
-    init_log();
+    messages_ptr: *const fil_Message,
+    messages_len: libc::size_t,
+    apply_kinds_ptr: *const u64,
+    apply_kinds_len: libc::size_t,
+) -> *mut fil_FvmMachineExecuteResponses {
+    catch_panic_response(|| {
+        init_log();
+
+        info!("fil_fvm_machine_execute_messages: start");
 
-    info!("fil_fvm_machine_flush_message: start");
+        let mut batch_response = fil_FvmMachineExecuteResponses::default();
+        batch_response.error_index = -1;
 
-    let machines = FVM_MAP.lock().unwrap();
-    let machine = machines.get(&machine_id);
-    match machine {
-        Some(machine) => {
-            todo!("execute message")
+        if messages_len != apply_kinds_len {
+            batch_response.status_code = FCPResponseStatus::FCPUnclassifiedError;
+            batch_response.error_msg =
+                rust_str_to_c_str(format!("messages and apply_kinds length mismatch"));
+            return raw_ptr(batch_response);
         }
-        None => {
-            todo!("invalid machine id")
+
+        let messages = std::slice::from_raw_parts(messages_ptr, messages_len);
+        let apply_kinds = std::slice::from_raw_parts(apply_kinds_ptr, apply_kinds_len);
+
+        let (slot, generation) = unpack_handle(machine_id);
+        let mut shard = shard_for(slot).lock().unwrap();
+        let found = match shard.get_mut(&slot) {
+            Some(found) if found.generation == generation => found,
+            _ => {
+                batch_response.status_code = FCPResponseStatus::FCPUnclassifiedError;
+                batch_response.error_msg = rust_str_to_c_str(format!("invalid machine id"));
+                return raw_ptr(batch_response);
+            }
+        };
+
+        let mut responses = Vec::with_capacity(messages_len);
+        let mut error_index: i64 = -1;
+        // Once an explicit message hard-fails we stop applying further
+        // explicit messages, but implicit ones (cron, reward, etc.) keep
+        // being applied for the rest of the batch.
+        let mut explicit_failed = false;
+
+        for (i, (message, apply_kind)) in messages.iter().zip(apply_kinds.iter()).enumerate() {
+            let apply_kind = if *apply_kind == 0 {
+                ApplyKind::Explicit
+            } else {
+                ApplyKind::Implicit
+            };
+
+            let mut response = fil_FvmMachineExecuteResponse::default();
+
+            if apply_kind == ApplyKind::Explicit && explicit_failed {
+                response.status_code = FCPResponseStatus::FCPUnclassifiedError;
+                response.error_msg =
+                    rust_str_to_c_str(format!("skipped: an earlier explicit message failed"));
+                responses.push(response);
+                continue;
+            }
+
+            let message = match convert_fil_message_to_message(*message) {
+                Ok(x) => x,
+                Err(err) => {
+                    response.status_code = FCPResponseStatus::FCPUnclassifiedError;
+                    response.error_msg = rust_str_to_c_str(format!("{:?}", err));
+                    responses.push(response);
+                    if apply_kind == ApplyKind::Explicit {
+                        if error_index < 0 {
+                            error_index = i as i64;
+                        }
+                        explicit_failed = true;
+                    }
+                    continue;
+                }
+            };
+
+            // See the single-message entry point for why this resets the
+            // budget on the machine's own `Store` instead of adding fuel to
+            // a shared `Engine`.
+            if found.fuel_limit > 0 {
+                if let Err(err) = found.machine.store_mut().set_fuel(found.fuel_limit) {
+                    response.status_code = FCPResponseStatus::FCPUnclassifiedError;
+                    response.error_msg = rust_str_to_c_str(format!("{:?}", err));
+                    responses.push(response);
+                    if apply_kind == ApplyKind::Explicit {
+                        if error_index < 0 {
+                            error_index = i as i64;
+                        }
+                        explicit_failed = true;
+                    }
+                    continue;
+                }
+            }
+
+            match found.machine.execute_message(message, apply_kind) {
+                Ok(apply_ret) => {
+                    response.status_code = FCPResponseStatus::FCPNoError;
+                    fill_execute_response(&mut response, apply_ret);
+                    if found.fuel_limit > 0 {
+                        response.fuel_remaining =
+                            found.machine.store_mut().get_fuel().unwrap_or(0);
+                    }
+                    responses.push(response);
+                }
+                Err(err) => {
+                    response.status_code = FCPResponseStatus::FCPUnclassifiedError;
+                    response.error_msg = rust_str_to_c_str(format!("{:?}", err));
+                    responses.push(response);
+                    if apply_kind == ApplyKind::Explicit {
+                        if error_index < 0 {
+                            error_index = i as i64;
+                        }
+                        explicit_failed = true;
+                    }
+                }
+            }
         }
-    }
 
-    info!("fil_fvm_machine_flush_message: end");
-    // })
+        batch_response.status_code = FCPResponseStatus::FCPNoError;
+        batch_response.error_index = error_index;
+        let (responses_ptr, responses_len) = vec_into_raw_parts(responses);
+        batch_response.responses_ptr = responses_ptr;
+        batch_response.responses_len = responses_len;
+
+        info!("fil_fvm_machine_execute_messages: end");
+
+        raw_ptr(batch_response)
+    })
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn fil_fvm_machine_finish_message(
+    machine_id: u64,
+) -> *mut fil_FvmMachineFlushResponse {
+    catch_panic_response(|| {
+        init_log();
+
+        info!("fil_fvm_machine_flush_message: start");
+
+        let mut response = fil_FvmMachineFlushResponse::default();
+
+        let (slot, generation) = unpack_handle(machine_id);
+        let mut shard = shard_for(slot).lock().unwrap();
+        match shard.get_mut(&slot) {
+            Some(found) if found.generation == generation => match found.machine.flush() {
+                Ok(state_root) => {
+                    let (state_root_ptr, state_root_len) =
+                        bytes_into_raw_parts(state_root.to_bytes());
+                    response.status_code = FCPResponseStatus::FCPNoError;
+                    response.state_root_ptr = state_root_ptr;
+                    response.state_root_len = state_root_len;
+                }
+                Err(err) => {
+                    response.status_code = FCPResponseStatus::FCPUnclassifiedError;
+                    response.error_msg = rust_str_to_c_str(format!("{:?}", err));
+                }
+            },
+            _ => {
+                response.status_code = FCPResponseStatus::FCPUnclassifiedError;
+                response.error_msg = rust_str_to_c_str(format!("invalid machine id"));
+            }
+        }
+
+        info!("fil_fvm_machine_flush_message: end");
+
+        raw_ptr(response)
+    })
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn fil_destroy_fvm_supported_features_response(
+    ptr: *mut fil_FvmFeaturesResponse,
+) {
+    let _ = Box::from_raw(ptr);
 }
 
 #[no_mangle]
@@ -256,9 +626,52 @@ pub unsafe extern "C" fn fil_destroy_drop_fvm_machine_response(
     let _ = Box::from_raw(ptr);
 }
 
+#[no_mangle]
+pub unsafe extern "C" fn fil_destroy_fvm_machine_flush_response(
+    ptr: *mut fil_FvmMachineFlushResponse,
+) {
+    let response = Box::from_raw(ptr);
+    if !response.state_root_ptr.is_null() {
+        let _ = Vec::from_raw_parts(
+            response.state_root_ptr,
+            response.state_root_len,
+            response.state_root_len,
+        );
+    }
+}
+
 #[no_mangle]
 pub unsafe extern "C" fn fil_destroy_fvm_machine_execute_response(
     ptr: *mut fil_FvmMachineExecuteResponse,
 ) {
-    let _ = Box::from_raw(ptr);
+    let response = Box::from_raw(ptr);
+    if !response.return_ptr.is_null() {
+        let _ = Vec::from_raw_parts(response.return_ptr, response.return_len, response.return_len);
+    }
+    if !response.failure_info.is_null() {
+        let _ = std::ffi::CString::from_raw(response.failure_info);
+    }
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn fil_destroy_fvm_machine_execute_responses(
+    ptr: *mut fil_FvmMachineExecuteResponses,
+) {
+    let batch = Box::from_raw(ptr);
+    if !batch.responses_ptr.is_null() {
+        let responses =
+            Vec::from_raw_parts(batch.responses_ptr, batch.responses_len, batch.responses_len);
+        for response in responses {
+            if !response.return_ptr.is_null() {
+                let _ = Vec::from_raw_parts(
+                    response.return_ptr,
+                    response.return_len,
+                    response.return_len,
+                );
+            }
+            if !response.failure_info.is_null() {
+                let _ = std::ffi::CString::from_raw(response.failure_info);
+            }
+        }
+    }
 }
\ No newline at end of file
@@ -0,0 +1,186 @@
+use drop_struct_macro_derive::DropStructMacro;
+use ffi_toolkit::FCPResponseStatus;
+use fvm_shared::address::Address;
+use fvm_shared::econ::TokenAmount;
+use fvm_shared::MethodNum;
+
+/// FvmRegisteredVersion is the version of the FVM engine to instantiate for
+/// a given machine. Only V1 exists today.
+#[repr(C)]
+#[derive(Debug, Clone, Copy)]
+pub enum fil_FvmRegisteredVersion {
+    V1 = 1,
+}
+
+/// A single message to be applied against a machine, in the C layout the
+/// cgo bindings construct.
+#[repr(C)]
+#[derive(Debug, Clone, Copy)]
+pub struct fil_Message {
+    pub from: [u8; 32],
+    pub from_len: libc::size_t,
+    pub to: [u8; 32],
+    pub to_len: libc::size_t,
+
+    pub sequence: u64,
+
+    pub value_hi: u64,
+    pub value_lo: u64,
+
+    pub gas_limit: i64,
+    pub gas_fee_cap_hi: u64,
+    pub gas_fee_cap_lo: u64,
+    pub gas_premium_hi: u64,
+    pub gas_premium_lo: u64,
+
+    pub method_num: u64,
+
+    pub params_ptr: *const u8,
+    pub params_len: libc::size_t,
+}
+
+pub(crate) fn convert_fil_message_to_message(
+    msg: fil_Message,
+) -> Result<fvm::message::Message, anyhow::Error> {
+    let from = Address::from_bytes(&msg.from[..msg.from_len])?;
+    let to = Address::from_bytes(&msg.to[..msg.to_len])?;
+    let params = unsafe { std::slice::from_raw_parts(msg.params_ptr, msg.params_len) }.to_vec();
+
+    Ok(fvm::message::Message {
+        version: 0,
+        from,
+        to,
+        sequence: msg.sequence,
+        value: TokenAmount::from(u128_from_hi_lo(msg.value_hi, msg.value_lo)),
+        method_num: msg.method_num as MethodNum,
+        params: params.into(),
+        gas_limit: msg.gas_limit,
+        gas_fee_cap: TokenAmount::from(u128_from_hi_lo(msg.gas_fee_cap_hi, msg.gas_fee_cap_lo)),
+        gas_premium: TokenAmount::from(u128_from_hi_lo(msg.gas_premium_hi, msg.gas_premium_lo)),
+    })
+}
+
+pub(crate) fn u128_from_hi_lo(hi: u64, lo: u64) -> u128 {
+    ((hi as u128) << 64) | lo as u128
+}
+
+pub(crate) fn token_amount_to_hi_lo(amount: &TokenAmount) -> (u64, u64) {
+    let value = amount.atto().to_u128().unwrap_or(u128::MAX);
+    ((value >> 64) as u64, value as u64)
+}
+
+/// Response of [`super::machine::fil_fvm_supported_features`], letting a
+/// host check what a given `NetworkVersion` supports before it commits to
+/// creating a machine with it.
+#[repr(C)]
+#[derive(Default, DropStructMacro)]
+pub struct fil_FvmFeaturesResponse {
+    pub status_code: FCPResponseStatus,
+    pub error_msg: *mut libc::c_char,
+
+    pub min_network_version: u32,
+    pub max_network_version: u32,
+
+    /// The actor bundle a host should load to drive this network version,
+    /// or 0 if the version isn't supported at all. This is a coarse,
+    /// whole-bundle version rather than a per-method capability set: a
+    /// host negotiates by fetching/pinning the matching bundle, not by
+    /// probing individual methods.
+    pub actors_bundle_version: u32,
+    pub actors_bundle_supported: bool,
+    pub implicit_messages_supported: bool,
+}
+
+#[repr(C)]
+#[derive(Default, DropStructMacro)]
+pub struct fil_CreateFvmMachineResponse {
+    pub status_code: FCPResponseStatus,
+    pub error_msg: *mut libc::c_char,
+
+    pub machine_id: u64,
+}
+
+#[repr(C)]
+#[derive(Default, DropStructMacro)]
+pub struct fil_DropFvmMachineResponse {
+    pub status_code: FCPResponseStatus,
+    pub error_msg: *mut libc::c_char,
+}
+
+/// Engine and memory tuning knobs for a machine, supplied by the host at
+/// creation time instead of being hardcoded.
+#[repr(C)]
+#[derive(Debug, Clone, Copy)]
+pub struct fil_FvmConfig {
+    pub initial_pages: u64,
+    pub max_pages: u64,
+
+    /// Upper bound on the wasmtime native stack, in bytes. Zero keeps
+    /// wasmtime's own default.
+    pub max_wasm_stack: u64,
+
+    /// Fuel budget applied to every message executed on this machine via
+    /// wasmtime's fuel metering. Zero disables the budget (unlimited).
+    pub fuel_limit: u64,
+}
+
+/// Response of [`super::machine::fil_fvm_machine_execute_message`], carrying
+/// the relevant fields of `fvm::machine::ApplyRet` across the FFI boundary.
+#[repr(C)]
+#[derive(Default, DropStructMacro)]
+pub struct fil_FvmMachineExecuteResponse {
+    pub status_code: FCPResponseStatus,
+    pub error_msg: *mut libc::c_char,
+
+    pub exit_code: u64,
+
+    pub return_ptr: *mut u8,
+    pub return_len: libc::size_t,
+
+    pub gas_used: u64,
+
+    pub penalty_hi: u64,
+    pub penalty_lo: u64,
+
+    pub miner_tip_hi: u64,
+    pub miner_tip_lo: u64,
+
+    /// Set when the message application failed with a backtrace, e.g. an
+    /// actor method aborted. Null when there is no failure info.
+    pub failure_info: *mut libc::c_char,
+
+    /// Fuel left in the wasmtime budget after executing this message, or
+    /// zero when the machine wasn't configured with a fuel limit.
+    pub fuel_remaining: u64,
+}
+
+/// Response of [`super::machine::fil_fvm_machine_execute_messages`], the
+/// batch counterpart of [`fil_FvmMachineExecuteResponse`].
+#[repr(C)]
+#[derive(Default, DropStructMacro)]
+pub struct fil_FvmMachineExecuteResponses {
+    pub status_code: FCPResponseStatus,
+    pub error_msg: *mut libc::c_char,
+
+    /// Index into `responses` (which holds one entry per input message) of
+    /// the first explicit message that hard-failed, after which later
+    /// explicit messages were skipped, or -1 if no explicit message failed.
+    /// Failures of implicit messages are reported only in their own
+    /// response, not here.
+    pub error_index: i64,
+
+    pub responses_ptr: *mut fil_FvmMachineExecuteResponse,
+    pub responses_len: libc::size_t,
+}
+
+/// Response of [`super::machine::fil_fvm_machine_finish_message`], carrying
+/// the CID of the state root produced by flushing the machine.
+#[repr(C)]
+#[derive(Default, DropStructMacro)]
+pub struct fil_FvmMachineFlushResponse {
+    pub status_code: FCPResponseStatus,
+    pub error_msg: *mut libc::c_char,
+
+    pub state_root_ptr: *mut u8,
+    pub state_root_len: libc::size_t,
+}